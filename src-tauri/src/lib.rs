@@ -1,107 +1,363 @@
+// This file depends on crates that must be declared in src-tauri/Cargo.toml
+// (not present in this checkout): `rand` (thread_rng/gen_range), `netstat2`
+// (native socket enumeration in kill_process_on_port), `nix` with the
+// `signal` feature (cfg(unix) SIGTERM/SIGKILL), `windows` with the
+// `Win32_Foundation` and `Win32_System_Threading` features (cfg(windows)
+// TerminateProcess), `tauri_plugin_shell` (both as a Cargo dependency and
+// via `tauri::Builder::plugin(tauri_plugin_shell::init())`) for its
+// `process`/`ShellExt` sidecar surface, `reqwest` (health polling), and
+// `tokio` (the `time` feature, for the async sleeps used throughout).
+use rand::Rng;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
 use std::process::{Command, Stdio};
-use std::sync::Mutex;
-use std::time::Duration;
-use tauri::Manager;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
+
+#[cfg(unix)]
+use nix::sys::signal::{self, Signal};
+#[cfg(unix)]
+use nix::unistd::Pid;
+
+/// The sidecar path declared under `externalBin` in `tauri.conf.json`.
+/// `sidecar()` matches this string against that entry verbatim (it is not
+/// just a friendly name), then resolves it to the PyInstaller-built backend
+/// sitting next to the app executable, appending the target triple. Must
+/// stay identical to the `externalBin` entry or `sidecar()` fails and the
+/// app falls back to `spawn_dev_backend` (logging the failure as an error).
+const BACKEND_SIDECAR_NAME: &str = "binaries/python-backend";
+
+/// Event emitted to the frontend for every line the backend writes to
+/// stdout or stderr.
+const BACKEND_LOG_EVENT: &str = "backend-log";
+
+/// Event emitted to the frontend whenever the backend's `BackendStatus`
+/// changes.
+const BACKEND_STATUS_EVENT: &str = "backend-status";
+
+/// How many of the most recent backend log lines to keep in memory for
+/// `get_backend_logs`, regardless of how many the frontend missed.
+const LOG_BUFFER_CAPACITY: usize = 1000;
+
+/// Coarse backend lifecycle state, stored as a lock-free atomic so the
+/// frontend (and `backend_status`) can read it without contending on the
+/// process mutex or making a network call.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+enum BackendStatus {
+    Stopped = 0,
+    Starting = 1,
+    Ready = 2,
+    Crashed = 3,
+    /// The supervisor has decided to restart a crashed backend and is
+    /// about to call `start_python_backend` again, distinct from `Crashed`
+    /// (given up, awaiting the next tick or cooldown) and `Starting` (the
+    /// restart is now actually underway inside `start_python_backend`).
+    Restarting = 4,
+}
+
+impl BackendStatus {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => BackendStatus::Starting,
+            2 => BackendStatus::Ready,
+            3 => BackendStatus::Crashed,
+            4 => BackendStatus::Restarting,
+            _ => BackendStatus::Stopped,
+        }
+    }
+}
+
+/// A running backend process, however it was launched.
+///
+/// In production the backend runs as a bundled sidecar binary; in dev mode
+/// we fall back to invoking the Python source tree directly so developers
+/// can run straight from a checkout. Both need to be killed and waited on the same way, so
+/// the rest of the app talks to this enum instead of either type directly.
+enum BackendProcess {
+    Sidecar {
+        child: CommandChild,
+        /// Flipped by the `CommandEvent` reader task when it sees
+        /// `CommandEvent::Terminated`, since `CommandChild` itself has no
+        /// `try_wait`-style poll.
+        exited: Arc<AtomicBool>,
+    },
+    Direct(std::process::Child),
+}
+
+impl BackendProcess {
+    fn pid(&self) -> u32 {
+        match self {
+            BackendProcess::Sidecar { child, .. } => child.pid(),
+            BackendProcess::Direct(child) => child.id(),
+        }
+    }
+}
 
 // State to track the Python backend process
 struct PythonBackend {
-    process: Mutex<Option<std::process::Child>>,
+    process: Mutex<Option<BackendProcess>>,
+    log_buffer: Mutex<VecDeque<String>>,
+    status: AtomicU8,
 }
 
-/// Kill any process using the specified port
-/// This handles orphaned processes from previous crashes
-fn kill_process_on_port(port: u16) -> Result<(), String> {
-    println!("Checking for processes on port {}...", port);
+/// Update the backend's lock-free status and notify the frontend.
+fn set_backend_status(app_handle: &tauri::AppHandle, backend: &PythonBackend, status: BackendStatus) {
+    backend.status.store(status as u8, Ordering::SeqCst);
+    let _ = app_handle.emit(BACKEND_STATUS_EVENT, status);
+}
 
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    {
-        // Use lsof to find processes using the port
-        let output = Command::new("lsof")
-            .args(&["-ti", &format!(":{}", port)])
-            .output()
-            .map_err(|e| format!("Failed to check port (lsof not available?): {}", e))?;
-
-        if output.status.success() && !output.stdout.is_empty() {
-            let pid_str = String::from_utf8_lossy(&output.stdout);
-            let pids: Vec<&str> = pid_str.trim().split('\n').collect();
-
-            for pid in pids {
-                if !pid.trim().is_empty() {
-                    println!("Killing process on port {}: PID {}", port, pid.trim());
-                    let _ = Command::new("kill")
-                        .args(&["-9", pid.trim()])
-                        .output();
-                }
+/// Record a line of backend output: print it (so it's still visible when
+/// running from a terminal), forward it to the frontend as a
+/// `backend-log` event, and keep it in the bounded ring buffer backing
+/// `get_backend_logs`.
+fn record_backend_log(app_handle: &tauri::AppHandle, line: String) {
+    println!("{}", line);
+
+    if let Err(e) = app_handle.emit(BACKEND_LOG_EVENT, &line) {
+        eprintln!("Failed to emit backend log event: {}", e);
+    }
+
+    if let Some(backend) = app_handle.try_state::<PythonBackend>() {
+        if let Ok(mut buffer) = backend.log_buffer.lock() {
+            if buffer.len() >= LOG_BUFFER_CAPACITY {
+                buffer.pop_front();
             }
-            // Give processes time to die
-            std::thread::sleep(Duration::from_millis(500));
-        } else {
-            println!("Port {} is free", port);
+            buffer.push_back(line);
+        }
+    }
+}
+
+/// Default grace period passed to `terminate_backend`: how long to wait
+/// for the backend to exit after a polite SIGTERM/taskkill before giving
+/// up and sending SIGKILL instead.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+const GRACEFUL_SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Send the polite "please exit" signal: SIGTERM on unix, a non-forceful
+/// `taskkill` on windows. Best-effort — logs and swallows errors, since the
+/// caller always has a hard-kill fallback.
+fn send_graceful_stop_signal(pid: u32) {
+    #[cfg(unix)]
+    {
+        if let Err(e) = signal::kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+            eprintln!("Failed to send SIGTERM to backend (PID {}): {}", pid, e);
         }
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(windows)]
     {
-        // Use netstat to find processes using the port
-        let output = Command::new("netstat")
-            .args(&["-ano"])
-            .output()
-            .map_err(|e| format!("Failed to check port: {}", e))?;
-
-        if output.status.success() {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            let mut killed_pids = std::collections::HashSet::new();
-
-            for line in output_str.lines() {
-                if line.contains(&format!(":{}", port)) && line.contains("LISTENING") {
-                    if let Some(pid) = line.split_whitespace().last() {
-                        if !killed_pids.contains(pid) {
-                            println!("Killing process on port {}: PID {}", port, pid);
-                            let _ = Command::new("taskkill")
-                                .args(&["/F", "/PID", pid])
-                                .output();
-                            killed_pids.insert(pid.to_string());
+        let result = Command::new("taskkill")
+            .args(&["/PID", &pid.to_string()])
+            .output();
+        if let Err(e) = result {
+            eprintln!("Failed to send taskkill to backend (PID {}): {}", pid, e);
+        }
+    }
+}
+
+/// Ask the backend process to exit cleanly, then fall back to a hard kill.
+///
+/// Sends SIGTERM (unix) or a non-forceful `taskkill` (windows) first so the
+/// FastAPI/uvicorn process gets a chance to flush state and release the
+/// port, then polls until it exits or `grace` elapses, at which point it
+/// escalates to a hard kill (SIGKILL). The grace-period wait is an async
+/// sleep rather than `std::thread::sleep`, so awaiting this from a Tauri
+/// command doesn't tie up an async-runtime worker thread for up to `grace`.
+async fn terminate_backend(process: BackendProcess, grace: Duration) -> Result<(), String> {
+    let pid = process.pid();
+    send_graceful_stop_signal(pid);
+    let deadline = Instant::now() + grace;
+
+    match process {
+        BackendProcess::Direct(mut child) => {
+            loop {
+                match child.try_wait() {
+                    Ok(Some(_)) => {
+                        println!("Backend process {} exited gracefully", pid);
+                        return Ok(());
+                    }
+                    Ok(None) => {
+                        if Instant::now() >= deadline {
+                            break;
                         }
+                        tokio::time::sleep(GRACEFUL_SHUTDOWN_POLL_INTERVAL).await;
                     }
+                    Err(e) => return Err(format!("Failed to poll backend process: {}", e)),
                 }
             }
-            if !killed_pids.is_empty() {
-                std::thread::sleep(Duration::from_millis(500));
-            } else {
-                println!("Port {} is free", port);
+
+            println!(
+                "Backend process {} did not exit within {:?}, sending SIGKILL",
+                pid, grace
+            );
+            child
+                .kill()
+                .map_err(|e| format!("Failed to force-kill backend process: {}", e))?;
+            let _ = child.wait();
+            Ok(())
+        }
+        BackendProcess::Sidecar { child, exited } => {
+            loop {
+                if exited.load(Ordering::SeqCst) {
+                    println!("Backend sidecar {} exited gracefully", pid);
+                    return Ok(());
+                }
+                if Instant::now() >= deadline {
+                    break;
+                }
+                tokio::time::sleep(GRACEFUL_SHUTDOWN_POLL_INTERVAL).await;
+            }
+
+            println!(
+                "Backend sidecar {} did not exit within {:?}, sending SIGKILL",
+                pid, grace
+            );
+            child
+                .kill()
+                .map_err(|e| format!("Failed to force-kill backend sidecar: {}", e))
+        }
+    }
+}
+
+/// Force-kill a process by PID. Unlike `terminate_backend`, this
+/// skips the polite SIGTERM/taskkill step entirely: it only ever runs
+/// against orphaned processes from a previous crash, not the backend we
+/// are actively managing, so there's nothing to flush.
+#[cfg(unix)]
+fn force_kill_pid(pid: u32) -> Result<(), String> {
+    signal::kill(Pid::from_raw(pid as i32), Signal::SIGKILL)
+        .map_err(|e| format!("Failed to kill PID {}: {}", pid, e))
+}
+
+#[cfg(windows)]
+fn force_kill_pid(pid: u32) -> Result<(), String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_TERMINATE, false, pid)
+            .map_err(|e| format!("Failed to open PID {}: {:?}", pid, e))?;
+        let result = TerminateProcess(handle, 1)
+            .map_err(|e| format!("Failed to terminate PID {}: {:?}", pid, e));
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+/// Kill any process listening on the specified port.
+/// This handles orphaned processes from previous crashes.
+///
+/// Port-to-PID discovery goes through `netstat2`'s native socket table
+/// instead of shelling out to `lsof`/`netstat`, so it behaves identically
+/// across platforms and doesn't depend on those binaries being installed.
+fn kill_process_on_port(port: u16) -> Result<(), String> {
+    println!("Checking for processes on port {}...", port);
+
+    let sockets = netstat2::get_sockets_info(
+        netstat2::AddressFamilyFlags::IPV4 | netstat2::AddressFamilyFlags::IPV6,
+        netstat2::ProtocolFlags::TCP,
+    )
+    .map_err(|e| format!("Failed to enumerate sockets: {}", e))?;
+
+    let mut pids = std::collections::HashSet::new();
+    for socket in sockets {
+        if let netstat2::ProtocolSocketInfo::Tcp(tcp) = socket.protocol_socket_info {
+            // Only processes actually listening on the port, not ones
+            // merely holding a
+            // TIME_WAIT or established connection that happens to use it.
+            if tcp.local_port == port && tcp.state == netstat2::TcpState::Listen {
+                pids.extend(socket.associated_pids);
             }
         }
     }
 
+    if pids.is_empty() {
+        println!("Port {} is free", port);
+        return Ok(());
+    }
+
+    for pid in pids {
+        println!("Killing process on port {}: PID {}", port, pid);
+        if let Err(e) = force_kill_pid(pid) {
+            eprintln!("{}", e);
+        }
+    }
+
+    // Give processes time to die
+    std::thread::sleep(Duration::from_millis(500));
     Ok(())
 }
 
-/// Wait for backend to be ready by polling the health endpoint
-async fn wait_for_backend_ready(max_retries: u32, delay_ms: u64) -> Result<(), String> {
+/// Default tuning for `wait_for_backend_ready`'s exponential backoff.
+const BACKEND_READY_BASE_DELAY: Duration = Duration::from_millis(200);
+const BACKEND_READY_BACKOFF_FACTOR: f64 = 2.0;
+const BACKEND_READY_MAX_DELAY: Duration = Duration::from_secs(5);
+const BACKEND_READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Apply random jitter of ±(delay/2) so that multiple backends restarting
+/// at once (e.g. after a crash) don't all poll in lockstep.
+fn jittered(delay: Duration) -> Duration {
+    let half = delay.as_secs_f64() / 2.0;
+    let jitter = rand::thread_rng().gen_range(-half..=half);
+    Duration::from_secs_f64((delay.as_secs_f64() + jitter).max(0.0))
+}
+
+/// Wait for the backend to be ready by polling the health endpoint with
+/// exponential backoff and jitter between attempts.
+///
+/// The delay between polls starts at `base_delay`, is multiplied by
+/// `factor` after each failed attempt up to `max_delay`, and is jittered
+/// by ±half its value so restarts don't synchronize. Gives up once
+/// `timeout` has elapsed since the call began, setting the backend
+/// status to `Crashed`. Sets it to `Ready` on success and returns the
+/// elapsed time, for logging.
+async fn wait_for_backend_ready(
+    app_handle: &tauri::AppHandle,
+    backend: &PythonBackend,
+    base_delay: Duration,
+    factor: f64,
+    max_delay: Duration,
+    timeout: Duration,
+) -> Result<Duration, String> {
     println!("Waiting for backend to be ready...");
 
-    for attempt in 1..=max_retries {
-        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    let start = Instant::now();
+    let mut delay = base_delay;
+    let mut attempt = 0u32;
+
+    loop {
+        attempt += 1;
+        tokio::time::sleep(jittered(delay)).await;
 
         match reqwest::get("http://localhost:8000/health").await {
             Ok(response) if response.status().is_success() => {
-                println!("✓ Backend is ready!");
-                return Ok(());
+                let elapsed = start.elapsed();
+                println!("✓ Backend is ready after {:?} ({} attempts)", elapsed, attempt);
+                set_backend_status(app_handle, backend, BackendStatus::Ready);
+                return Ok(elapsed);
             }
             Ok(response) => {
-                println!("Backend responded with status: {} (attempt {}/{})",
-                    response.status(), attempt, max_retries);
+                println!("Backend responded with status: {} (attempt {}, elapsed {:?})",
+                    response.status(), attempt, start.elapsed());
             }
             Err(e) => {
-                if attempt < max_retries {
-                    println!("Waiting for backend... (attempt {}/{}) - {}",
-                        attempt, max_retries, e);
-                }
+                println!("Waiting for backend... (attempt {}, elapsed {:?}) - {}",
+                    attempt, start.elapsed(), e);
             }
         }
-    }
 
-    Err("Backend failed to start within timeout period".to_string())
+        if start.elapsed() >= timeout {
+            set_backend_status(app_handle, backend, BackendStatus::Crashed);
+            return Err("Backend failed to start within timeout period".to_string());
+        }
+
+        delay = std::cmp::min(delay.mul_f64(factor), max_delay);
+    }
 }
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
@@ -110,17 +366,12 @@ fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
-#[tauri::command]
-async fn start_python_backend(
-    python_backend: tauri::State<'_, PythonBackend>,
-) -> Result<String, String> {
-    println!("Starting Python backend...");
-
-    // Kill any orphaned processes on port 8000 first
-    if let Err(e) = kill_process_on_port(8000) {
-        eprintln!("Warning: Failed to clean up port 8000: {}", e);
-    }
-
+/// Launch the backend from its source tree with a local interpreter.
+///
+/// This is the dev-mode fallback: it hunts for `.venv/bin/python` or falls
+/// back to `python3` and runs `main.py` out of a relative `python-backend`
+/// directory, which only exists in a checkout, never in a packaged app.
+fn spawn_dev_backend(app_handle: &tauri::AppHandle) -> Result<BackendProcess, String> {
     // Get the app directory - need to go up one level from src-tauri in dev mode
     let current_dir = std::env::current_dir()
         .map_err(|e| format!("Failed to get current directory: {}", e))?;
@@ -157,11 +408,88 @@ async fn start_python_backend(
     command
         .arg("main.py")
         .current_dir(&python_backend_dir)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .env("PORT", "8000")
         .env("TAURI_MANAGED", "true");  // Signal to disable reload
 
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start Python backend: {}", e))?;
+
+    if let Some(stdout) = child.stdout.take() {
+        let app_handle = app_handle.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                record_backend_log(&app_handle, line);
+            }
+        });
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let app_handle = app_handle.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                record_backend_log(&app_handle, line);
+            }
+        });
+    }
+
+    Ok(BackendProcess::Direct(child))
+}
+
+/// Launch the backend as a bundled sidecar binary.
+///
+/// `tauri_plugin_shell`'s sidecar API resolves `BACKEND_SIDECAR_NAME`
+/// against the `externalBin` entries in `tauri.conf.json`, appending the
+/// target triple, so this only succeeds when a PyInstaller-built backend
+/// binary was actually bundled next to the app executable.
+fn spawn_sidecar_backend(app_handle: &tauri::AppHandle) -> Result<BackendProcess, String> {
+    let (mut rx, child) = app_handle
+        .shell()
+        .sidecar(BACKEND_SIDECAR_NAME)
+        .map_err(|e| format!("Backend sidecar not available: {}", e))?
+        .env("PORT", "8000")
+        .env("TAURI_MANAGED", "true")
+        .spawn()
+        .map_err(|e| format!("Failed to spawn backend sidecar: {}", e))?;
+
+    let exited = Arc::new(AtomicBool::new(false));
+
+    let app_handle = app_handle.clone();
+    let exited_writer = exited.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(bytes) | CommandEvent::Stderr(bytes) => {
+                    let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                    record_backend_log(&app_handle, line);
+                }
+                CommandEvent::Terminated(payload) => {
+                    println!("Backend sidecar exited: {:?}", payload);
+                    exited_writer.store(true, Ordering::SeqCst);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
+    Ok(BackendProcess::Sidecar { child, exited })
+}
+
+#[tauri::command]
+async fn start_python_backend(
+    app_handle: tauri::AppHandle,
+    python_backend: tauri::State<'_, PythonBackend>,
+) -> Result<String, String> {
+    println!("Starting Python backend...");
+    set_backend_status(&app_handle, &python_backend, BackendStatus::Starting);
+
+    // Kill any orphaned processes on port 8000 first
+    if let Err(e) = kill_process_on_port(8000) {
+        eprintln!("Warning: Failed to clean up port 8000: {}", e);
+    }
+
     // Kill any previously tracked process
     let existing_process = {
         let mut state_guard = python_backend
@@ -171,35 +499,62 @@ async fn start_python_backend(
         state_guard.take()
     };
 
-    if let Some(mut existing) = existing_process {
-        if let Err(kill_err) = existing.kill() {
-            eprintln!("Failed to kill existing backend process: {}", kill_err);
+    if let Some(existing) = existing_process {
+        if let Err(kill_err) = terminate_backend(existing, GRACEFUL_SHUTDOWN_TIMEOUT).await {
+            eprintln!("Failed to terminate existing backend process: {}", kill_err);
         }
-        let _ = existing.wait();
     }
 
-    match command.spawn() {
-        Ok(process) => {
-            let pid = process.id();
-            {
-                let mut state_guard = python_backend
-                    .process
-                    .lock()
-                    .map_err(|e| format!("Failed to lock backend state: {}", e))?;
-                *state_guard = Some(process);
-            }
-
-            println!("Python backend started with PID: {}", pid);
-
-            // Wait for backend to be ready (30 retries * 500ms = 15 seconds max)
-            wait_for_backend_ready(30, 500).await?;
+    // Then kill any orphaned processes still on port 8000
+    if let Err(e) = kill_process_on_port(8000) {
+        eprintln!("Warning: Failed to clean up port 8000: {}", e);
+    }
 
-            Ok("Python backend started successfully".to_string())
-        }
+    // Prefer the bundled sidecar; fall back to the dev-mode source launch
+    // when no sidecar binary was packaged (i.e. during local development).
+    let process = match spawn_sidecar_backend(&app_handle) {
+        Ok(process) => process,
         Err(e) => {
-            Err(format!("Failed to start Python backend: {}", e))
+            // In production this means the bundled binary is missing or
+            // broken and the app has no backend at all, so it's logged as
+            // an error rather than the routine dev-mode println!s around
+            // it — don't let it look like ordinary fallback chatter.
+            eprintln!("{} - falling back to dev-mode source launch", e);
+            match spawn_dev_backend(&app_handle) {
+                Ok(process) => process,
+                Err(e) => {
+                    set_backend_status(&app_handle, &python_backend, BackendStatus::Crashed);
+                    return Err(e);
+                }
+            }
         }
+    };
+    let pid = process.pid();
+
+    {
+        let mut state_guard = python_backend
+            .process
+            .lock()
+            .map_err(|e| format!("Failed to lock backend state: {}", e))?;
+        *state_guard = Some(process);
     }
+
+    println!("Python backend started with PID: {}", pid);
+
+    let elapsed = wait_for_backend_ready(
+        &app_handle,
+        &python_backend,
+        BACKEND_READY_BASE_DELAY,
+        BACKEND_READY_BACKOFF_FACTOR,
+        BACKEND_READY_MAX_DELAY,
+        BACKEND_READY_TIMEOUT,
+    )
+    .await?;
+
+    Ok(format!(
+        "Python backend started successfully in {:?}",
+        elapsed
+    ))
 }
 
 #[tauri::command]
@@ -211,29 +566,169 @@ async fn check_backend_health() -> Result<bool, String> {
     }
 }
 
+/// How often the supervisor polls the tracked process and health endpoint.
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Consecutive failed health checks (with the process still alive) before
+/// the backend is considered crashed and a restart is triggered.
+const SUPERVISOR_FAILURE_THRESHOLD: u32 = 3;
+/// Base cooldown between restart attempts, doubled for each restart the
+/// supervisor has made so far (capped; the count is never reset) so a
+/// permanently-broken backend doesn't spin-restart forever.
+const SUPERVISOR_RESTART_COOLDOWN: Duration = Duration::from_secs(30);
+const SUPERVISOR_MAX_COOLDOWN_DOUBLINGS: u32 = 5;
+
+/// Background task, spawned once from `setup`, that watches the backend
+/// and restarts it if it dies.
+///
+/// A tick is skipped entirely while the backend is `Stopped` (an
+/// intentional shutdown, e.g. the window closing without quitting the
+/// app), `Restarting`, or `Starting` (still inside `start_python_backend`,
+/// whose `wait_for_backend_ready` can legitimately take close to its own
+/// timeout). Otherwise each tick reaps
+/// the tracked process and calls the health endpoint. A healthy check
+/// promotes the status back to `Ready` (covering a backend that recovers
+/// on its own, e.g. after a slow start or a transient blip, without going
+/// through a full restart). The backend is considered crashed either
+/// immediately (the process has exited) or after
+/// `SUPERVISOR_FAILURE_THRESHOLD` consecutive failed health checks, at
+/// which point the status is set to `Restarting` and `start_python_backend`
+/// is re-invoked, subject to a restart cooldown that backs off with each
+/// restart made so far. `Restarting` lets the frontend distinguish "crashed
+/// and about to retry" from `Crashed` alone ("gave up, waiting out the
+/// cooldown").
+fn spawn_backend_supervisor(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut consecutive_failures = 0u32;
+        let mut restart_count = 0u32;
+        let mut last_restart: Option<Instant> = None;
+
+        loop {
+            tokio::time::sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+            let backend_state = app_handle.state::<PythonBackend>();
+
+            match BackendStatus::from_u8(backend_state.status.load(Ordering::SeqCst)) {
+                BackendStatus::Stopped => {
+                    consecutive_failures = 0;
+                    continue;
+                }
+                BackendStatus::Starting | BackendStatus::Restarting => continue,
+                BackendStatus::Ready | BackendStatus::Crashed => {}
+            }
+
+            let process_exited = {
+                let mut guard = match backend_state.process.lock() {
+                    Ok(guard) => guard,
+                    Err(e) => {
+                        eprintln!("Supervisor failed to lock backend state: {}", e);
+                        continue;
+                    }
+                };
+                match guard.as_mut() {
+                    Some(BackendProcess::Direct(child)) => matches!(child.try_wait(), Ok(Some(_))),
+                    Some(BackendProcess::Sidecar { exited, .. }) => exited.load(Ordering::SeqCst),
+                    None => true,
+                }
+            };
+
+            let healthy = !process_exited && check_backend_health().await.unwrap_or(false);
+
+            if healthy {
+                consecutive_failures = 0;
+                if BackendStatus::from_u8(backend_state.status.load(Ordering::SeqCst)) != BackendStatus::Ready {
+                    set_backend_status(&app_handle, &backend_state, BackendStatus::Ready);
+                }
+                continue;
+            }
+
+            consecutive_failures += 1;
+            if !process_exited && consecutive_failures < SUPERVISOR_FAILURE_THRESHOLD {
+                println!(
+                    "Backend health check failed ({}/{})",
+                    consecutive_failures, SUPERVISOR_FAILURE_THRESHOLD
+                );
+                continue;
+            }
+
+            set_backend_status(&app_handle, &backend_state, BackendStatus::Crashed);
+
+            if let Some(last) = last_restart {
+                let cooldown = SUPERVISOR_RESTART_COOLDOWN
+                    * 2u32.pow(restart_count.min(SUPERVISOR_MAX_COOLDOWN_DOUBLINGS));
+                if last.elapsed() < cooldown {
+                    println!(
+                        "Backend crashed but restart cooldown is active ({:?} remaining)",
+                        cooldown - last.elapsed()
+                    );
+                    continue;
+                }
+            }
+
+            restart_count += 1;
+            consecutive_failures = 0;
+            last_restart = Some(Instant::now());
+
+            println!("Restarting backend (attempt {})", restart_count);
+            set_backend_status(&app_handle, &backend_state, BackendStatus::Restarting);
+
+            match start_python_backend(app_handle.clone(), backend_state).await {
+                Ok(msg) => println!("Supervisor restarted backend: {}", msg),
+                Err(e) => eprintln!("Supervisor failed to restart backend: {}", e),
+            }
+        }
+    });
+}
+
+/// Return the last `LOG_BUFFER_CAPACITY` lines of backend stdout/stderr,
+/// oldest first. Useful for a frontend log panel that missed earlier
+/// `backend-log` events (e.g. opened after the backend already started).
+#[tauri::command]
+fn get_backend_logs(python_backend: tauri::State<'_, PythonBackend>) -> Result<Vec<String>, String> {
+    let buffer = python_backend
+        .log_buffer
+        .lock()
+        .map_err(|e| format!("Failed to lock backend log buffer: {}", e))?;
+    Ok(buffer.iter().cloned().collect())
+}
+
+/// Read the current backend status. Lock-free: only touches the atomic
+/// status field, so it never contends with the spawn/shutdown mutex or
+/// makes a network call like `check_backend_health` does.
+#[tauri::command]
+fn backend_status(python_backend: tauri::State<'_, PythonBackend>) -> BackendStatus {
+    BackendStatus::from_u8(python_backend.status.load(Ordering::SeqCst))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .manage(PythonBackend {
             process: Mutex::new(None),
+            log_buffer: Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY)),
+            status: AtomicU8::new(BackendStatus::Stopped as u8),
         })
         .invoke_handler(tauri::generate_handler![
             greet,
             start_python_backend,
-            check_backend_health
+            check_backend_health,
+            get_backend_logs,
+            backend_status
         ])
         .setup(|app| {
             // Start Python backend on app startup
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
                 let backend_state = app_handle.state::<PythonBackend>();
-                match start_python_backend(backend_state).await {
+                match start_python_backend(app_handle.clone(), backend_state).await {
                     Ok(msg) => println!("✓ {}", msg),
                     Err(err) => eprintln!("✗ Failed to start backend: {}", err),
                 }
             });
 
+            // Watch the backend and restart it if it dies
+            spawn_backend_supervisor(app.handle().clone());
+
             // Add graceful shutdown on window close
             let backend_handle = app.handle().clone();
             if let Some(window) = app.get_webview_window("main") {
@@ -241,16 +736,26 @@ pub fn run() {
                     if let tauri::WindowEvent::CloseRequested { .. } = event {
                         println!("Window closing, cleaning up backend...");
                         let backend_state = backend_handle.state::<PythonBackend>();
-                        if let Ok(mut guard) = backend_state.process.lock() {
-                            if let Some(mut process) = guard.take() {
-                                if let Err(e) = process.kill() {
-                                    eprintln!("Failed to kill backend process: {}", e);
-                                } else {
-                                    println!("Backend process terminated gracefully");
-                                }
-                                let _ = process.wait();
-                            }
+                        // Set Stopped before taking the process and
+                        // starting the (slow) terminate below, so a supervisor tick that
+                        // runs concurrently sees the shutdown immediately
+                        // and doesn't race to restart what we're killing.
+                        set_backend_status(&backend_handle, &backend_state, BackendStatus::Stopped);
+                        let process = match backend_state.process.lock() {
+                            Ok(mut guard) => guard.take(),
+                            Err(_) => None,
                         };
+                        // Run the (awaited) grace-period wait on the async
+                        // runtime instead of blocking this window-event
+                        // callback, which runs on the UI thread.
+                        if let Some(process) = process {
+                            tauri::async_runtime::spawn(async move {
+                                match terminate_backend(process, GRACEFUL_SHUTDOWN_TIMEOUT).await {
+                                    Ok(()) => println!("Backend process terminated gracefully"),
+                                    Err(e) => eprintln!("Failed to terminate backend process: {}", e),
+                                }
+                            });
+                        }
                     }
                 });
             }